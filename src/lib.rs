@@ -8,7 +8,7 @@ pub use log4rs;
 
 use std::env;
 use std::fmt;
-use std::fs::{DirBuilder, File, OpenOptions, remove_file};
+use std::fs::{DirBuilder, File, OpenOptions, read_dir, remove_file};
 use std::io::{self, Stdout, Write, BufWriter};
 use std::path::{Path, PathBuf};
 use once_cell::sync::Lazy;
@@ -20,6 +20,8 @@ use std::os::unix::fs::symlink;
 use std::os::windows::fs::symlink_file as symlink;
 
 use time::{OffsetDateTime, Time, Duration};
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use log::{Level, Record, LevelFilter};
 use log4rs::append::Append;
 use log4rs::filter::{Filter, Response as FilterResponse};
@@ -38,14 +40,18 @@ static YMD: Lazy<Vec<time::format_description::FormatItem>> = Lazy::new(|| {
     time::format_description::parse_borrowed::<2>(r"[year]-[month]-[day]").unwrap()
 });
 
+static YMD_H: Lazy<Vec<time::format_description::FormatItem>> = Lazy::new(|| {
+    time::format_description::parse_borrowed::<2>(r"[year]-[month]-[day]_[hour]").unwrap()
+});
+
+static YMD_HM: Lazy<Vec<time::format_description::FormatItem>> = Lazy::new(|| {
+    time::format_description::parse_borrowed::<2>(r"[year]-[month]-[day]_[hour][minute]").unwrap()
+});
+
 fn time_now() -> String {
     OffsetDateTime::now_local().unwrap().format(&HMS).unwrap()
 }
 
-fn date_now() -> String {
-    OffsetDateTime::now_local().unwrap().format(&YMD).unwrap()
-}
-
 fn ensure_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
     if path.as_ref().is_dir() {
         return Ok(());
@@ -53,6 +59,29 @@ fn ensure_dir<P: AsRef<Path>>(path: P) -> io::Result<()> {
     DirBuilder::new().recursive(true).create(path)
 }
 
+/// The conventional system log directory for `appname` on this platform, if
+/// one is known.
+fn platform_default_log_dir(appname: &str) -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        env::var_os("PROGRAMDATA")
+            .or_else(|| env::var_os("LOCALAPPDATA"))
+            .map(|base| Path::new(&base).join(appname).join("logs"))
+    }
+    #[cfg(target_os = "macos")]
+    {
+        Some(PathBuf::from("/Library/Logs").join(appname))
+    }
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        Some(PathBuf::from("/var/log").join(appname))
+    }
+    #[cfg(not(any(target_os = "windows", unix)))]
+    {
+        None
+    }
+}
+
 
 /// A log4rs appender that writes ANSI colored log messages to stdout.
 pub struct ConsoleAppender {
@@ -157,57 +186,360 @@ impl Append for PlainConsoleAppender {
 }
 
 
-type Writer = SimpleWriter<BufWriter<File>>;
+/// A `Write` wrapper that keeps a running count of the bytes that have
+/// passed through it, so the current file size can be checked without a
+/// `stat` syscall on every append.
+#[derive(Debug)]
+struct CountingWriter {
+    inner: BufWriter<File>,
+    count: u64,
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+type Writer = SimpleWriter<CountingWriter>;
+
+/// `(open file, next rollover time, bytes written to the current segment,
+/// current segment number, path of the current segment)`.
+type FileState = (Option<Writer>, OffsetDateTime, u64, u32, Option<PathBuf>);
+
+/// How often a [`RollingFileAppender`] rolls over to a new file, independent
+/// of any size-based rollover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RolloverInterval {
+    Minutely,
+    Hourly,
+    Daily,
+}
+
+impl RolloverInterval {
+    fn truncate(self, dt: OffsetDateTime) -> OffsetDateTime {
+        match self {
+            RolloverInterval::Minutely => dt.replace_time(
+                Time::from_hms(dt.hour(), dt.minute(), 0).unwrap()),
+            RolloverInterval::Hourly => dt.replace_time(
+                Time::from_hms(dt.hour(), 0, 0).unwrap()),
+            RolloverInterval::Daily => dt.replace_time(Time::MIDNIGHT),
+        }
+    }
+
+    fn step(self) -> Duration {
+        match self {
+            RolloverInterval::Minutely => Duration::minutes(1),
+            RolloverInterval::Hourly => Duration::hours(1),
+            RolloverInterval::Daily => Duration::days(1),
+        }
+    }
+
+    fn date_suffix(self) -> String {
+        let now = OffsetDateTime::now_local().unwrap();
+        match self {
+            RolloverInterval::Minutely => now.format(&YMD_HM).unwrap(),
+            RolloverInterval::Hourly => now.format(&YMD_H).unwrap(),
+            RolloverInterval::Daily => now.format(&YMD).unwrap(),
+        }
+    }
+}
 
-/// A log4rs appender that writes to daily rolling logfiles with the date
-/// used as a suffix in the filename.
+/// A log4rs appender that writes to rolling logfiles with the date (and,
+/// depending on the configured [`RolloverInterval`], time) used as a suffix
+/// in the filename.
+///
+/// If `max_size` is set, the current segment is rolled over early (within
+/// the same interval) once it exceeds that many bytes, producing additional
+/// numbered segments (`prefix-YYYY-MM-DD.1.log`, `.2.log`, ...).  If
+/// `max_files` and/or `max_age_days` are set, old segments are pruned
+/// after each rollover.  If `compress` is set, each rolled-over segment is
+/// gzip-compressed to `.log.gz` in a background thread.
+///
+/// Construct one with [`RollingFileAppender::builder`].
 #[derive(Debug)]
 pub struct RollingFileAppender {
-    dir:     PathBuf,
-    prefix:  String,
-    link_fn: PathBuf,
-    file:    Mutex<(Option<Writer>, OffsetDateTime)>,
-    pattern: PatternEncoder,
+    dir:          PathBuf,
+    prefix:       String,
+    link_fn:      PathBuf,
+    file:         Mutex<FileState>,
+    pattern:      PatternEncoder,
+    interval:     RolloverInterval,
+    max_size:     Option<u64>,
+    max_files:    Option<usize>,
+    max_age_days: Option<u64>,
+    compress:     bool,
 }
 
 impl RollingFileAppender {
+    /// Construct an appender with default (daily rollover, no size limit or
+    /// retention cap) settings.
+    ///
+    /// Panics if the local UTC offset cannot be determined; use
+    /// [`RollingFileAppender::builder`] to handle that case gracefully.
     pub fn new(dir: &Path, prefix: &str) -> RollingFileAppender {
-        let thisday = OffsetDateTime::now_local().unwrap().replace_time(Time::MIDNIGHT);
-        let roll_at = thisday + Duration::days(1);
-        let pattern = PatternEncoder::new("{d(%H:%M:%S,%f)(local)} : {l:<5} : {X(thread)}{m}{n}");
-        let link_fn = dir.join("current");
-        let prefix = prefix.replace('/', "-");
-        RollingFileAppender { dir: dir.to_path_buf(),
-                              prefix,
-                              link_fn,
-                              file: Mutex::new((None, roll_at)),
-                              pattern, }
+        RollingFileAppenderBuilder::new(dir, prefix).build()
+            .expect("could not determine local time to start rolling file appender")
     }
 
-    fn rollover(&self, file_opt: &mut Option<Writer>, roll_at: &mut OffsetDateTime) -> io::Result<()> {
-        file_opt.take(); // will drop the file if open
-        let full = format!("{}-{}.log", self.prefix, date_now());
-        let new_fn = self.dir.join(full);
+    /// Start building an appender with non-default options.
+    pub fn builder(dir: &Path, prefix: &str) -> RollingFileAppenderBuilder {
+        RollingFileAppenderBuilder::new(dir, prefix)
+    }
+
+    fn segment_filename(&self, segment: u32) -> String {
+        if segment == 0 {
+            format!("{}-{}.log", self.prefix, self.interval.date_suffix())
+        } else {
+            format!("{}-{}.{}.log", self.prefix, self.interval.date_suffix(), segment)
+        }
+    }
+
+    fn open_segment(&self, segment: u32) -> io::Result<(Writer, u64, PathBuf)> {
+        let new_fn = self.dir.join(self.segment_filename(segment));
         let fp = OpenOptions::new()
             .create(true).write(true).append(true)
             .open(&new_fn)?;
-        *file_opt = Some(SimpleWriter(BufWriter::new(fp)));
+        let size = fp.metadata()?.len();
         let _ = remove_file(&self.link_fn);
         let _ = symlink(new_fn.file_name().unwrap(), &self.link_fn);
-        *roll_at += Duration::days(1);
+        Ok((SimpleWriter(CountingWriter { inner: BufWriter::new(fp), count: size }), size, new_fn))
+    }
+
+    fn rollover(&self, file_opt: &mut Option<Writer>, roll_at: &mut OffsetDateTime,
+                bytes: &mut u64, segment: &mut u32, path: &mut Option<PathBuf>) -> io::Result<()> {
+        file_opt.take(); // will drop the file if open
+        let old_path = path.take();
+        *segment = 0;
+        let (writer, size, new_path) = self.open_segment(*segment)?;
+        *file_opt = Some(writer);
+        *bytes = size;
+        *path = Some(new_path);
+        *roll_at += self.interval.step();
+        self.maybe_compress(old_path);
+        self.cleanup()
+    }
+
+    fn size_rollover(&self, file_opt: &mut Option<Writer>, bytes: &mut u64, segment: &mut u32,
+                      path: &mut Option<PathBuf>) -> io::Result<()> {
+        file_opt.take();
+        let old_path = path.take();
+        *segment += 1;
+        let (writer, size, new_path) = self.open_segment(*segment)?;
+        *file_opt = Some(writer);
+        *bytes = size;
+        *path = Some(new_path);
+        self.maybe_compress(old_path);
+        self.cleanup()
+    }
+
+    /// Compress the just-closed segment to `.log.gz` in a background thread,
+    /// if `compress` is enabled.  The currently active segment is never
+    /// passed here, so it is never compressed while still being written.
+    fn maybe_compress(&self, old_path: Option<PathBuf>) {
+        if !self.compress {
+            return;
+        }
+        if let Some(old_path) = old_path {
+            std::thread::spawn(move || {
+                let _ = compress_and_remove(&old_path);
+            });
+        }
+    }
+
+    /// If `name` is one of this appender's own segments, i.e. matches
+    /// `{prefix}-<date>[.<segment>].log[.gz]`, return the date portion of
+    /// the filename (e.g. `2026-07-28` or, for hourly/minutely intervals,
+    /// `2026-07-28_14`) and the segment number (0 if there is none).
+    ///
+    /// Anchoring on the prefix alone isn't enough to recognize our own
+    /// files: a sibling appender such as `<prefix>-errors` also starts with
+    /// `{prefix}-`, so this additionally checks that what follows the
+    /// prefix starts with a digit, as a date does.
+    fn segment_sort_key(&self, name: &str) -> Option<(String, u32)> {
+        let rest = name.strip_prefix(&format!("{}-", self.prefix))?;
+        if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        let rest = rest.strip_suffix(".log.gz").or_else(|| rest.strip_suffix(".log"))?;
+        match rest.rsplit_once('.') {
+            Some((date, segment)) if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) =>
+                Some((date.to_string(), segment.parse().unwrap_or(0))),
+            _ => Some((rest.to_string(), 0)),
+        }
+    }
+
+    /// Scan the log directory for segments belonging to this appender and
+    /// remove the oldest ones until the retention limits are satisfied.
+    ///
+    /// Ordering and age are derived from the date/segment embedded in the
+    /// filename rather than filesystem mtime, since `compress_and_remove`
+    /// rewrites a segment under a new name (giving it a fresh mtime) without
+    /// changing how old its *content* is.
+    fn cleanup(&self) -> io::Result<()> {
+        if self.max_files.is_none() && self.max_age_days.is_none() {
+            return Ok(());
+        }
+        let mut segments = Vec::new();
+        for entry in read_dir(&self.dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if let Some((date, segment)) = self.segment_sort_key(&name) {
+                segments.push((entry.path(), date, segment));
+            }
+        }
+        segments.sort_by(|(_, d1, s1), (_, d2, s2)| (d1, s1).cmp(&(d2, s2)));
+
+        if let Some(max_age_days) = self.max_age_days {
+            let cutoff = OffsetDateTime::now_utc().date()
+                .checked_sub(Duration::days(max_age_days as i64));
+            if let Some(cutoff) = cutoff {
+                segments.retain(|(path, date, _)| {
+                    let keep = segment_date(date).is_none_or(|d| d >= cutoff);
+                    if !keep {
+                        let _ = remove_file(path);
+                    }
+                    keep
+                });
+            }
+        }
+        if let Some(max_files) = self.max_files {
+            while segments.len() > max_files {
+                let (path, _, _) = segments.remove(0);
+                let _ = remove_file(path);
+            }
+        }
         Ok(())
     }
 }
 
+/// Parse the leading `YYYY-MM-DD` out of a segment's date portion (which may
+/// carry an `_HH` or `_HHMM` suffix for hourly/minutely intervals); age
+/// limits only need day granularity.
+fn segment_date(date_part: &str) -> Option<time::Date> {
+    time::Date::parse(date_part.get(..10)?, &YMD).ok()
+}
+
+/// Gzip-compress `path` to `path` with a `.gz` extension appended, then
+/// remove the uncompressed original.  Run off the logging hot path, in a
+/// spawned thread, since rolled-over logfiles can be sizeable.
+fn compress_and_remove(path: &Path) -> io::Result<()> {
+    let gz_path = path.with_extension("log.gz");
+    let mut input = File::open(path)?;
+    let output = File::create(&gz_path)?;
+    let mut encoder = GzEncoder::new(output, Compression::default());
+    io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?;
+    remove_file(path)
+}
+
+/// Builder for [`RollingFileAppender`], so new options can be added without
+/// breaking existing callers of [`RollingFileAppender::new`].
+#[derive(Debug, Clone)]
+pub struct RollingFileAppenderBuilder {
+    dir:          PathBuf,
+    prefix:       String,
+    link_name:    String,
+    interval:     RolloverInterval,
+    max_size:     Option<u64>,
+    max_files:    Option<usize>,
+    max_age_days: Option<u64>,
+    compress:     bool,
+}
+
+impl RollingFileAppenderBuilder {
+    pub fn new(dir: &Path, prefix: &str) -> Self {
+        Self { dir: dir.to_path_buf(),
+               prefix: prefix.replace('/', "-"),
+               link_name: "current".into(),
+               interval: RolloverInterval::Daily,
+               max_size: None,
+               max_files: None,
+               max_age_days: None,
+               compress: false, }
+    }
+
+    /// Name of the symlink (created in `dir`) that always points at the
+    /// newest segment (default: `current`).  Appenders sharing a directory
+    /// must each use a distinct name so they don't clobber each other's
+    /// symlink.
+    pub fn link_name(mut self, link_name: impl Into<String>) -> Self {
+        self.link_name = link_name.into();
+        self
+    }
+
+    /// Set how often the appender rolls over to a new file (default: daily).
+    pub fn interval(mut self, interval: RolloverInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Roll over early, within the same interval, once the current segment
+    /// exceeds this many bytes.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Keep at most this many segments, deleting the oldest ones after each
+    /// rollover.
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = Some(max_files);
+        self
+    }
+
+    /// Delete segments older than this many days after each rollover.
+    pub fn max_age_days(mut self, max_age_days: u64) -> Self {
+        self.max_age_days = Some(max_age_days);
+        self
+    }
+
+    /// Gzip-compress each segment once it is rolled over (default: off).
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    pub fn build(self) -> io::Result<RollingFileAppender> {
+        let now = OffsetDateTime::now_local()
+            .map_err(io::Error::other)?;
+        let roll_at = self.interval.truncate(now) + self.interval.step();
+        let pattern = PatternEncoder::new("{d(%H:%M:%S,%f)(local)} : {l:<5} : {X(thread)}{m}{n}");
+        let link_fn = self.dir.join(&self.link_name);
+        Ok(RollingFileAppender { dir: self.dir,
+                                 prefix: self.prefix,
+                                 link_fn,
+                                 file: Mutex::new((None, roll_at, 0, 0, None)),
+                                 pattern,
+                                 interval: self.interval,
+                                 max_size: self.max_size,
+                                 max_files: self.max_files,
+                                 max_age_days: self.max_age_days,
+                                 compress: self.compress, })
+    }
+}
+
 impl Append for RollingFileAppender {
     fn append(&self, record: &Record) -> anyhow::Result<()> {
-        let (ref mut file_opt, ref mut roll_at) = *self.file.lock();
+        let (ref mut file_opt, ref mut roll_at, ref mut bytes, ref mut segment, ref mut path) = *self.file.lock();
         if file_opt.is_none() || OffsetDateTime::now_utc() >= *roll_at {
-            self.rollover(file_opt, roll_at)?;
+            self.rollover(file_opt, roll_at, bytes, segment, path)?;
+        } else if let Some(max_size) = self.max_size {
+            if *bytes > max_size {
+                self.size_rollover(file_opt, bytes, segment, path)?;
+            }
         }
         let fp = file_opt.as_mut().unwrap();
         self.pattern.encode(fp, record)?;
         fp.flush()?;
+        *bytes = fp.0.count;
         Ok(())
     }
 
@@ -234,6 +566,28 @@ impl Append for JournalAppender {
     fn flush(&self) { }
 }
 
+/// A log4rs filter that rejects records less severe than a minimum level.
+#[derive(Debug, Clone, Copy)]
+pub struct MinLevelFilter {
+    level: Level,
+}
+
+impl MinLevelFilter {
+    pub fn new(level: Level) -> Self {
+        Self { level }
+    }
+}
+
+impl Filter for MinLevelFilter {
+    fn filter(&self, record: &Record) -> FilterResponse {
+        if record.level() <= self.level {
+            FilterResponse::Neutral
+        } else {
+            FilterResponse::Reject
+        }
+    }
+}
+
 /// A log4rs filter for filtering by target.
 #[derive(Debug, Clone)]
 pub struct TargetFilter {
@@ -299,7 +653,13 @@ fn parse_filter_config(cfg: String) -> TargetFilter {
 /// If `show_appname` is true, the appname is prepended to console messages.
 /// If `debug` is true, debug messages are output.  If `use_stdout` is true, a
 /// `ConsoleAppender` is created to log to stdout.  If `use_journal` is true,
-/// messages will be written to journald.
+/// messages will be written to journald.  `rollover_interval` controls how
+/// often the logfile is rolled over (default: daily).  If `compress` is
+/// true, rolled-over logfiles are gzip-compressed.  If `error_log` is true,
+/// a second logfile (`<appname>-errors-DATE.log`) receives only `WARN` and
+/// `ERROR` records, for quick triage.  If `use_default_log_dir` is true and no
+/// `log_path`/`MLZ_LOG_PATH` is configured, a conventional OS-specific system
+/// log directory is used instead of disabling file logging.
 #[derive(Debug, Clone)]
 pub struct Settings {
     pub show_appname: bool,
@@ -307,6 +667,10 @@ pub struct Settings {
     pub use_stdout: bool,
     pub stdout_color: bool,
     pub use_journal: bool,
+    pub rollover_interval: RolloverInterval,
+    pub compress: bool,
+    pub error_log: bool,
+    pub use_default_log_dir: bool,
 }
 
 impl Default for Settings {
@@ -317,6 +681,10 @@ impl Default for Settings {
             use_stdout: true,
             stdout_color: true,
             use_journal: false,
+            rollover_interval: RolloverInterval::Daily,
+            compress: false,
+            error_log: false,
+            use_default_log_dir: false,
         }
     }
 }
@@ -326,8 +694,8 @@ impl Default for Settings {
 ///
 /// `log_path` is the base path for logfiles.  The `appname` is used as the base
 /// name for the logfiles, with the current day appended.  The logfile is rolled
-/// over on midnight.  A symbolic link named `current` always links to the
-/// latest file.
+/// over according to `settings.rollover_interval` (daily by default).  A
+/// symbolic link named `current` always links to the latest file.
 ///
 /// If `log_path` is `None`, no logfiles are written to disk.
 ///
@@ -357,17 +725,42 @@ pub fn init<P: AsRef<Path>>(log_path: Option<P>, appname: &str, settings: Settin
         }
     }
 
+    if log_path.is_none() && settings.use_default_log_dir && env::var_os("MLZ_LOG_PATH").is_none() {
+        if let Some(default_dir) = platform_default_log_dir(appname) {
+            if ensure_dir(&default_dir).is_ok() {
+                log_path = Some(default_dir);
+            }
+        }
+    }
+
     let filter = env::var("MLZ_LOG_FILTER").ok().map(parse_filter_config);
 
-    if let Some(p) = log_path {
-        ensure_dir(&p)?;
-        let file_appender = RollingFileAppender::new(&p, appname);
+    if let Some(ref p) = log_path {
+        ensure_dir(p)?;
+        let file_appender = RollingFileAppender::builder(p, appname)
+            .interval(settings.rollover_interval)
+            .compress(settings.compress)
+            .build()?;
         root_cfg = root_cfg.appender("file");
         let mut app_builder = Appender::builder();
         if let Some(ref f) = filter {
             app_builder = app_builder.filter(Box::new(f.clone()));
         }
         config = config.appender(app_builder.build("file", Box::new(file_appender)));
+
+        if settings.error_log {
+            let err_appender = RollingFileAppender::builder(p, &format!("{appname}-errors"))
+                .interval(settings.rollover_interval)
+                .compress(settings.compress)
+                .link_name("current-errors")
+                .build()?;
+            root_cfg = root_cfg.appender("errfile");
+            let mut err_builder = Appender::builder().filter(Box::new(MinLevelFilter::new(Level::Warn)));
+            if let Some(ref f) = filter {
+                err_builder = err_builder.filter(Box::new(f.clone()));
+            }
+            config = config.appender(err_builder.build("errfile", Box::new(err_appender)));
+        }
     }
     if settings.use_stdout {
         let appname_prefix = format!("[{appname}] ");
@@ -418,3 +811,66 @@ pub fn init<P: AsRef<Path>>(log_path: Option<P>, appname: &str, settings: Settin
 pub fn set_thread_prefix(prefix: impl Into<String>) {
     log_mdc::insert("thread", prefix.into());
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::remove_dir_all;
+
+    /// A fresh, empty scratch directory for a single test, named after it so
+    /// parallel test runs don't collide.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("mlzlog-test-{name}-{}", std::process::id()));
+        let _ = remove_dir_all(&dir);
+        DirBuilder::new().recursive(true).create(&dir).unwrap();
+        dir
+    }
+
+    fn today_ymd() -> String {
+        OffsetDateTime::now_local().unwrap().format(&YMD).unwrap()
+    }
+
+    fn record(level: Level) -> Record<'static> {
+        Record::builder().level(level).args(format_args!("message")).build()
+    }
+
+    #[test]
+    fn max_size_rolls_into_numbered_segments() {
+        let dir = test_dir("max_size_rolls_into_numbered_segments");
+        let appender = RollingFileAppender::builder(&dir, "app").max_size(1).build().unwrap();
+        for _ in 0..3 {
+            appender.append(&record(Level::Info)).unwrap();
+        }
+        let today = today_ymd();
+        assert!(dir.join(format!("app-{today}.log")).is_file());
+        assert!(dir.join(format!("app-{today}.1.log")).is_file());
+        assert!(dir.join(format!("app-{today}.2.log")).is_file());
+    }
+
+    #[test]
+    fn cleanup_honors_max_files() {
+        let dir = test_dir("cleanup_honors_max_files");
+        let appender = RollingFileAppender::builder(&dir, "app")
+            .max_size(1)
+            .max_files(2)
+            .build()
+            .unwrap();
+        for _ in 0..5 {
+            appender.append(&record(Level::Info)).unwrap();
+        }
+        let remaining = read_dir(&dir).unwrap()
+            .map(|e| e.unwrap().file_name())
+            .filter(|n| n != "current")
+            .count();
+        assert_eq!(remaining, 2);
+    }
+
+    #[test]
+    fn min_level_filter_passes_warn_and_error_rejects_info() {
+        let filter = MinLevelFilter::new(Level::Warn);
+        assert_eq!(filter.filter(&record(Level::Error)), FilterResponse::Neutral);
+        assert_eq!(filter.filter(&record(Level::Warn)), FilterResponse::Neutral);
+        assert_eq!(filter.filter(&record(Level::Info)), FilterResponse::Reject);
+    }
+}